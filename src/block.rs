@@ -0,0 +1,57 @@
+//! Const-evaluated block parsing and padding helpers shared by the
+//! [`crate::sha1`] and [`crate::sha256`] compression functions.
+
+/// A 64-byte (512-bit) tail buffer used to hold the bytes that didn't
+/// fill a whole block, until enough data (or the padding) arrives to
+/// process it.
+pub(crate) struct Blocks {
+    pub(crate) len: u32,
+    pub(crate) data: [u8; 64],
+}
+
+/// Parse 16 big-endian `u32` words out of a 64-byte window of `input`
+/// starting at `offset`.
+pub(crate) const fn as_block(input: &[u8], offset: usize) -> [u32; 16] {
+    let mut result = [0u32; 16];
+
+    let mut i = 0;
+    while i != 16 {
+        let off = offset + (i * 4);
+        result[i] = ((input[off] as u32) << 24)
+            | ((input[off + 1] as u32) << 16)
+            | ((input[off + 2] as u32) << 8)
+            | (input[off + 3] as u32);
+        i += 1;
+    }
+    result
+}
+
+/// Copy `num_elems` bytes from `slice` (starting at `offset`) to the
+/// front of `data`.
+pub(crate) const fn clone_from_slice<const N: usize>(
+    mut data: [u8; N],
+    slice: &[u8],
+    offset: usize,
+    num_elems: usize,
+) -> [u8; N] {
+    let mut i = 0;
+    while i < num_elems {
+        data[i] = slice[offset + i];
+        i += 1;
+    }
+    data
+}
+
+/// Copy all of `slice` into `data` starting at `offset`.
+pub(crate) const fn clone_into<const N: usize>(
+    mut data: [u8; N],
+    slice: &[u8],
+    offset: usize,
+) -> [u8; N] {
+    let mut i = 0;
+    while i < slice.len() {
+        data[offset + i] = slice[i];
+        i += 1;
+    }
+    data
+}
@@ -1,4 +1,4 @@
-//! A const evaluated sha1 function.
+//! Const evaluated sha1 and sha256 functions.
 //!
 //! # Use
 //!
@@ -12,6 +12,12 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod block;
+mod sha256;
+
+pub use sha256::{sha256, sha256_from_const_slice, Digest256};
+
+use block::{as_block, clone_from_slice, clone_into, Blocks};
 use core::fmt;
 
 /// A const evaluated sha1 function.
@@ -24,13 +30,7 @@ use core::fmt;
 /// }
 /// ```
 pub const fn sha1(data: &[u8]) -> Digest {
-    let state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
-    let blocks = Blocks {
-        len: 0,
-        data: [0; 64],
-    };
-    let (blocks, len, state) = process_blocks(blocks, data, data.len(), state);
-    digest(state, len, blocks)
+    Sha1Context::new().update(data).finalize()
 }
 
 /// A const evaluated sha1 function. The function differs from `sha1`
@@ -41,10 +41,10 @@ pub const fn sha1(data: &[u8]) -> Digest {
 ///
 /// ```
 /// const fn signature() -> const_sha1::Digest {
-///     const_sha1::sha1_from_const_slice(&const_sha1::ConstSlice::from_slice(stringify!(MyType).as_bytes()))
+///     const_sha1::sha1_from_const_slice::<64>(&const_sha1::ConstSlice::from_slice(stringify!(MyType).as_bytes()))
 /// }
 /// ```
-pub const fn sha1_from_const_slice(data: &ConstSlice) -> Digest {
+pub const fn sha1_from_const_slice<const N: usize>(data: &ConstSlice<N>) -> Digest {
     let state: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
     let blocks = Blocks {
         len: 0,
@@ -54,17 +54,96 @@ pub const fn sha1_from_const_slice(data: &ConstSlice) -> Digest {
     digest(state, len, blocks)
 }
 
-/// The size of the ConstSlice.
+/// Incremental const-evaluated sha1 hasher state, for folding several
+/// pieces of data into one digest without concatenating them into a
+/// single buffer first.
+///
+/// # Use
+///
+/// ```
+/// const fn signature() -> const_sha1::Digest {
+///     const_sha1::Sha1Context::new()
+///         .update(stringify!(MyType).as_bytes())
+///         .update(b":")
+///         .finalize()
+/// }
+/// ```
+pub struct Sha1Context {
+    state: [u32; 5],
+    blocks: Blocks,
+    len: usize,
+}
+
+impl Sha1Context {
+    /// Create a new, empty sha1 hasher state.
+    pub const fn new() -> Self {
+        Self {
+            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
+            blocks: Blocks {
+                len: 0,
+                data: [0; 64],
+            },
+            len: 0,
+        }
+    }
+
+    /// Fold `data` into the running hash, consuming whole 512-bit blocks
+    /// as they fill up and retaining the tail for the next `update` or
+    /// `finalize`.
+    pub const fn update(self, data: &[u8]) -> Self {
+        let Self {
+            mut blocks,
+            mut state,
+            mut len,
+        } = self;
+
+        let tail = blocks.len as usize;
+        let data = if tail == 0 {
+            data
+        } else if tail + data.len() < 64 {
+            blocks.data = clone_into(blocks.data, data, tail);
+            blocks.len += data.len() as u32;
+            return Self { blocks, state, len };
+        } else {
+            let (head, rest) = data.split_at(64 - tail);
+            blocks.data = clone_into(blocks.data, head, tail);
+            state = process_state(state, as_block(&blocks.data, 0));
+            blocks.len = 0;
+            len += 64;
+            rest
+        };
+
+        let (blocks, processed, state) = process_blocks(blocks, data, data.len(), state);
+        Self {
+            blocks,
+            state,
+            len: len + processed,
+        }
+    }
+
+    /// Finish the hash and return the digest of everything folded in so
+    /// far via `update`.
+    pub const fn finalize(self) -> Digest {
+        digest(self.state, self.len, self.blocks)
+    }
+}
+
+/// The size of [`ConstBuffer`], kept for source compatibility with the
+/// fixed-size `ConstSlice` this crate used to expose.
 pub const BUFFER_SIZE: usize = 1024;
 
-/// A buffer of a constant size suitable for use in const contexts
+/// A [`ConstSlice`] sized the way this crate's `ConstSlice` used to be
+/// before it grew a const-generic capacity. Kept for source compatibility.
+pub type ConstBuffer = ConstSlice<BUFFER_SIZE>;
+
+/// A buffer of a constant capacity `N` suitable for use in const contexts
 /// as a temporary replacement for slices.
-pub struct ConstSlice {
-    data: [u8; BUFFER_SIZE],
+pub struct ConstSlice<const N: usize> {
+    data: [u8; N],
     head: usize,
 }
 
-impl ConstSlice {
+impl<const N: usize> ConstSlice<N> {
     /// Convert a slice into a `ConstSlice`.
     pub const fn from_slice(slice: &[u8]) -> Self {
         let s = Self::new();
@@ -74,7 +153,7 @@ impl ConstSlice {
     /// Create an empty `ConstSlice`.
     pub const fn new() -> Self {
         Self {
-            data: [0; BUFFER_SIZE],
+            data: [0; N],
             head: 0,
         }
     }
@@ -99,8 +178,9 @@ impl ConstSlice {
         &self.data
     }
 
-    /// Push another `ConstSlice` on to the current buffer.
-    pub const fn push_other(self, other: Self) -> Self {
+    /// Push another `ConstSlice` on to the current buffer. The other
+    /// buffer's capacity need not match this one's.
+    pub const fn push_other<const M: usize>(self, other: ConstSlice<M>) -> Self {
         self.push_amount(other.as_slice(), other.len())
     }
 
@@ -115,53 +195,18 @@ impl ConstSlice {
     }
 }
 
-impl fmt::Debug for ConstSlice {
+impl<const N: usize> fmt::Debug for ConstSlice<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:x?}", &self.data[0..self.head])
     }
 }
 
-struct Blocks {
-    len: u32,
-    data: [u8; 64],
-}
-
 const fn process_blocks(
     mut blocks: Blocks,
     data: &[u8],
     data_len: usize,
     mut state: [u32; 5],
 ) -> (Blocks, usize, [u32; 5]) {
-    const fn as_block(input: &[u8], offset: usize) -> [u32; 16] {
-        let mut result = [0u32; 16];
-
-        let mut i = 0;
-        while i != 16 {
-            let off = offset + (i * 4);
-            result[i] = 0
-                | ((input[off + 3] as u32) << 0)
-                | ((input[off + 2] as u32) << 8)
-                | ((input[off + 1] as u32) << 16)
-                | ((input[off + 0] as u32) << 24);
-            i += 1;
-        }
-        result
-    }
-
-    const fn clone_from_slice_64(
-        mut data: [u8; 64],
-        slice: &[u8],
-        offset: usize,
-        num_elems: usize,
-    ) -> [u8; 64] {
-        let mut i = 0;
-        while i < num_elems {
-            data[i] = slice[offset + i];
-            i += 1;
-        }
-        data
-    }
-
     let mut len = 0;
     while len < data_len {
         let left = data_len - len;
@@ -170,7 +215,7 @@ const fn process_blocks(
             state = process_state(state, chunk_block);
             len += 64;
         } else {
-            blocks.data = clone_from_slice_64(blocks.data, data, len, left);
+            blocks.data = clone_from_slice(blocks.data, data, len, left);
             blocks.len = left as u32;
             break;
         }
@@ -274,44 +319,6 @@ const fn process_state(mut state: [u32; 5], block: [u32; 16]) -> [u32; 5] {
 }
 
 const fn digest(mut state: [u32; 5], len: usize, blocks: Blocks) -> Digest {
-    const fn clone_from_slice_128(
-        mut data: [u8; 128],
-        slice: &[u8],
-        offset: usize,
-        num_elems: usize,
-    ) -> [u8; 128] {
-        let mut i = 0;
-        while i < num_elems {
-            data[i] = slice[offset + i];
-            i += 1;
-        }
-        data
-    }
-
-    const fn clone_slice_128(mut data: [u8; 128], slice: &[u8], offset: usize) -> [u8; 128] {
-        let mut i = 0;
-        while i < slice.len() {
-            data[offset + i] = slice[i];
-            i += 1;
-        }
-        data
-    }
-
-    const fn as_block(input: &[u8], offset: usize) -> [u32; 16] {
-        let mut result = [0u32; 16];
-
-        let mut i = 0;
-        while i != 16 {
-            let off = offset + (i * 4);
-            result[i] = (input[off + 3] as u32)
-                | ((input[off + 2] as u32) << 8)
-                | ((input[off + 1] as u32) << 16)
-                | ((input[off] as u32) << 24);
-            i += 1;
-        }
-        result
-    }
-
     let bits = ((len as u64) + (blocks.len as u64)) * 8;
     let extra = [
         (bits >> 56) as u8,
@@ -325,14 +332,14 @@ const fn digest(mut state: [u32; 5], len: usize, blocks: Blocks) -> Digest {
     ];
     let mut last = [0; 128];
     let blocklen = blocks.len as usize;
-    last = clone_from_slice_128(last, &blocks.data, 0, blocklen);
+    last = clone_from_slice(last, &blocks.data, 0, blocklen);
     last[blocklen] = 0x80;
 
     if blocklen < 56 {
-        last = clone_slice_128(last, &extra, 56);
+        last = clone_into(last, &extra, 56);
         state = process_state(state, as_block(&last, 0));
     } else {
-        last = clone_slice_128(last, &extra, 120);
+        last = clone_into(last, &extra, 120);
         state = process_state(state, as_block(&last, 0));
         state = process_state(state, as_block(&last, 64));
     }
@@ -474,6 +481,67 @@ impl Digest {
             (self.data[4] >> 0) as u8,
         ]
     }
+
+    /// Parse a 40-character (lower- or upper-case) hex string into a
+    /// `Digest`, so an expected hash can be pinned as a compile-time
+    /// constant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not exactly 40 bytes long or contains a
+    /// non-hex-digit byte. In a `const` context this turns into a
+    /// compile error.
+    ///
+    /// # Use
+    ///
+    /// ```
+    /// const DATA: &[u8] = b"hello world";
+    /// const EXPECTED: const_sha1::Digest =
+    ///     const_sha1::Digest::from_hex(b"2aae6c35c94fcfb415dbe95f408b9ce91ee846ed");
+    /// const _: () = assert!(const_sha1::sha1(DATA).eq(&EXPECTED));
+    /// ```
+    pub const fn from_hex(s: &[u8]) -> Self {
+        if s.len() != 40 {
+            panic!("sha1 hex digest must be exactly 40 characters long");
+        }
+
+        let mut data = [0u32; 5];
+        let mut i = 0;
+        while i < 5 {
+            let off = i * 8;
+            data[i] = ((hex_digit(s[off]) as u32) << 28)
+                | ((hex_digit(s[off + 1]) as u32) << 24)
+                | ((hex_digit(s[off + 2]) as u32) << 20)
+                | ((hex_digit(s[off + 3]) as u32) << 16)
+                | ((hex_digit(s[off + 4]) as u32) << 12)
+                | ((hex_digit(s[off + 5]) as u32) << 8)
+                | ((hex_digit(s[off + 6]) as u32) << 4)
+                | (hex_digit(s[off + 7]) as u32);
+            i += 1;
+        }
+        Digest { data }
+    }
+
+    /// Compare two digests without branching on their contents, suitable
+    /// for pinning an expected hash with `assert!` in a `const` context.
+    pub const fn eq(&self, other: &Self) -> bool {
+        let mut diff = 0u32;
+        let mut i = 0;
+        while i < 5 {
+            diff |= self.data[i] ^ other.data[i];
+            i += 1;
+        }
+        diff == 0
+    }
+}
+
+const fn hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex digit in sha1 digest"),
+    }
 }
 
 impl fmt::Display for Digest {
@@ -539,9 +607,49 @@ mod tests {
         }
 
         for &(s, expected) in tests.iter().filter(|(s, _)| s.len() <= BUFFER_SIZE) {
-            let hash = sha1_from_const_slice(&ConstSlice::from_slice(s.as_bytes())).to_string();
+            let hash =
+                sha1_from_const_slice(&ConstBuffer::from_slice(s.as_bytes())).to_string();
 
             assert_eq!(hash, expected);
         }
     }
+
+    #[test]
+    fn context_matches_sha1_for_split_input() {
+        let s = "The quick brown fox jumps over the lazy dog";
+
+        for split in 0..=s.len() {
+            let (head, tail) = s.as_bytes().split_at(split);
+            let hash = Sha1Context::new()
+                .update(head)
+                .update(tail)
+                .finalize()
+                .to_string();
+
+            assert_eq!(hash, sha1(s.as_bytes()).to_string());
+        }
+    }
+
+    #[test]
+    fn from_hex_parses_digest() {
+        let hash = sha1(b"The quick brown fox jumps over the lazy dog");
+        let expected = Digest::from_hex(b"2fd4e1c67a2d28fced849ee1bb76e7391b93eb12");
+        let upper = Digest::from_hex(b"2FD4E1C67A2D28FCED849EE1BB76E7391B93EB12");
+
+        assert!(hash.eq(&expected));
+        assert!(hash.eq(&upper));
+        assert!(!hash.eq(&Digest::from_hex(b"0000000000000000000000000000000000000000")));
+    }
+
+    #[test]
+    #[should_panic(expected = "exactly 40 characters")]
+    fn from_hex_rejects_wrong_length() {
+        Digest::from_hex(b"too short");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex digit")]
+    fn from_hex_rejects_non_hex_digit() {
+        Digest::from_hex(b"zfd4e1c67a2d28fced849ee1bb76e7391b93eb12");
+    }
 }
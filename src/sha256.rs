@@ -0,0 +1,289 @@
+//! A const evaluated sha256 function.
+
+use crate::block::{as_block, clone_from_slice, clone_into, Blocks};
+use crate::ConstSlice;
+use core::fmt;
+
+/// A const evaluated sha256 function.
+///
+/// # Use
+///
+/// ```
+/// const fn signature() -> const_sha1::Digest256 {
+///     const_sha1::sha256(stringify!(MyType).as_bytes())
+/// }
+/// ```
+pub const fn sha256(data: &[u8]) -> Digest256 {
+    let state = H;
+    let blocks = Blocks {
+        len: 0,
+        data: [0; 64],
+    };
+    let (blocks, len, state) = process_blocks(blocks, data, data.len(), state);
+    digest(state, len, blocks)
+}
+
+/// A const evaluated sha256 function. The function differs from `sha256`
+/// only in taking its input as a [`ConstSlice`] of any capacity `N`; only
+/// the first `data.len()` bytes are hashed.
+///
+/// # Use
+///
+/// ```
+/// const fn signature() -> const_sha1::Digest256 {
+///     const_sha1::sha256_from_const_slice::<64>(&const_sha1::ConstSlice::from_slice(stringify!(MyType).as_bytes()))
+/// }
+/// ```
+pub const fn sha256_from_const_slice<const N: usize>(data: &ConstSlice<N>) -> Digest256 {
+    let state = H;
+    let blocks = Blocks {
+        len: 0,
+        data: [0; 64],
+    };
+    let (blocks, len, state) = process_blocks(blocks, data.as_slice(), data.len(), state);
+    digest(state, len, blocks)
+}
+
+const H: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+    0x5be0cd19,
+];
+
+#[rustfmt::skip]
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+const fn process_blocks(
+    mut blocks: Blocks,
+    data: &[u8],
+    data_len: usize,
+    mut state: [u32; 8],
+) -> (Blocks, usize, [u32; 8]) {
+    let mut len = 0;
+    while len < data_len {
+        let left = data_len - len;
+        if left >= 64 {
+            let chunk_block = as_block(data, len);
+            state = process_state(state, chunk_block);
+            len += 64;
+        } else {
+            blocks.data = clone_from_slice(blocks.data, data, len, left);
+            blocks.len = left as u32;
+            break;
+        }
+    }
+    (blocks, len, state)
+}
+
+const fn rotr(value: u32, bits: u32) -> u32 {
+    (value >> bits) | (value << (32 - bits))
+}
+
+const fn ch(e: u32, f: u32, g: u32) -> u32 {
+    (e & f) ^ (!e & g)
+}
+
+const fn maj(a: u32, b: u32, c: u32) -> u32 {
+    (a & b) ^ (a & c) ^ (b & c)
+}
+
+const fn big_sigma0(a: u32) -> u32 {
+    rotr(a, 2) ^ rotr(a, 13) ^ rotr(a, 22)
+}
+
+const fn big_sigma1(e: u32) -> u32 {
+    rotr(e, 6) ^ rotr(e, 11) ^ rotr(e, 25)
+}
+
+const fn sigma0(x: u32) -> u32 {
+    rotr(x, 7) ^ rotr(x, 18) ^ (x >> 3)
+}
+
+const fn sigma1(x: u32) -> u32 {
+    rotr(x, 17) ^ rotr(x, 19) ^ (x >> 10)
+}
+
+const fn process_state(mut state: [u32; 8], block: [u32; 16]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+    let mut i = 0;
+    while i < 16 {
+        w[i] = block[i];
+        i += 1;
+    }
+    while i < 64 {
+        w[i] = sigma1(w[i - 2])
+            .wrapping_add(w[i - 7])
+            .wrapping_add(sigma0(w[i - 15]))
+            .wrapping_add(w[i - 16]);
+        i += 1;
+    }
+
+    let mut a = state[0];
+    let mut b = state[1];
+    let mut c = state[2];
+    let mut d = state[3];
+    let mut e = state[4];
+    let mut f = state[5];
+    let mut g = state[6];
+    let mut h = state[7];
+
+    let mut t = 0;
+    while t < 64 {
+        let t1 = h
+            .wrapping_add(big_sigma1(e))
+            .wrapping_add(ch(e, f, g))
+            .wrapping_add(K[t])
+            .wrapping_add(w[t]);
+        let t2 = big_sigma0(a).wrapping_add(maj(a, b, c));
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+        t += 1;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+    state[5] = state[5].wrapping_add(f);
+    state[6] = state[6].wrapping_add(g);
+    state[7] = state[7].wrapping_add(h);
+    state
+}
+
+const fn digest(mut state: [u32; 8], len: usize, blocks: Blocks) -> Digest256 {
+    let bits = ((len as u64) + (blocks.len as u64)) * 8;
+    let extra = [
+        (bits >> 56) as u8,
+        (bits >> 48) as u8,
+        (bits >> 40) as u8,
+        (bits >> 32) as u8,
+        (bits >> 24) as u8,
+        (bits >> 16) as u8,
+        (bits >> 8) as u8,
+        (bits >> 0) as u8,
+    ];
+    let mut last = [0; 128];
+    let blocklen = blocks.len as usize;
+    last = clone_from_slice(last, &blocks.data, 0, blocklen);
+    last[blocklen] = 0x80;
+
+    if blocklen < 56 {
+        last = clone_into(last, &extra, 56);
+        state = process_state(state, as_block(&last, 0));
+    } else {
+        last = clone_into(last, &extra, 120);
+        state = process_state(state, as_block(&last, 0));
+        state = process_state(state, as_block(&last, 64));
+    }
+    Digest256 { data: state }
+}
+
+/// A sha256 digest
+pub struct Digest256 {
+    /// The sha256 digest's data
+    data: [u32; 8],
+}
+
+impl Digest256 {
+    /// Returns the 256 bit (32 byte) digest as a byte array.
+    pub const fn as_bytes(&self) -> [u8; 32] {
+        [
+            (self.data[0] >> 24) as u8,
+            (self.data[0] >> 16) as u8,
+            (self.data[0] >> 8) as u8,
+            (self.data[0] >> 0) as u8,
+            (self.data[1] >> 24) as u8,
+            (self.data[1] >> 16) as u8,
+            (self.data[1] >> 8) as u8,
+            (self.data[1] >> 0) as u8,
+            (self.data[2] >> 24) as u8,
+            (self.data[2] >> 16) as u8,
+            (self.data[2] >> 8) as u8,
+            (self.data[2] >> 0) as u8,
+            (self.data[3] >> 24) as u8,
+            (self.data[3] >> 16) as u8,
+            (self.data[3] >> 8) as u8,
+            (self.data[3] >> 0) as u8,
+            (self.data[4] >> 24) as u8,
+            (self.data[4] >> 16) as u8,
+            (self.data[4] >> 8) as u8,
+            (self.data[4] >> 0) as u8,
+            (self.data[5] >> 24) as u8,
+            (self.data[5] >> 16) as u8,
+            (self.data[5] >> 8) as u8,
+            (self.data[5] >> 0) as u8,
+            (self.data[6] >> 24) as u8,
+            (self.data[6] >> 16) as u8,
+            (self.data[6] >> 8) as u8,
+            (self.data[6] >> 0) as u8,
+            (self.data[7] >> 24) as u8,
+            (self.data[7] >> 16) as u8,
+            (self.data[7] >> 8) as u8,
+            (self.data[7] >> 0) as u8,
+        ]
+    }
+}
+
+impl fmt::Display for Digest256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for i in self.data.iter() {
+            write!(f, "{:08x}", i)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate alloc;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::ToString;
+
+    #[test]
+    fn it_works() {
+        let tests = [
+            (
+                "",
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                "The quick brown fox jumps over the lazy dog",
+                "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592",
+            ),
+            (
+                "testing\n",
+                "12a61f4e173fb3a11c05d6471f74728f76231b4a5fcd9667cef3af87a3ae4dc2",
+            ),
+        ];
+
+        for &(s, expected) in tests.iter() {
+            let hash = sha256(s.as_bytes()).to_string();
+
+            assert_eq!(hash, expected);
+        }
+
+        for &(s, expected) in tests.iter().filter(|(s, _)| s.len() <= crate::BUFFER_SIZE) {
+            let hash = sha256_from_const_slice(&crate::ConstBuffer::from_slice(s.as_bytes()))
+                .to_string();
+
+            assert_eq!(hash, expected);
+        }
+    }
+}